@@ -1,8 +1,23 @@
 use crate::prelude::*;
-use cblas_sys::cblas_sgemm;
+#[cfg(not(feature = "no-blas"))]
+use cblas_sys::{cblas_dgemm, cblas_sgemm};
 use num::NumCast;
 use std::ops::*;
 
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+);+ $(;)?) => {
+        $crate::matrix_stable::Matrix::from_rows([$([$($x),+]),+])
+    };
+}
+
+#[macro_export]
+macro_rules! vector {
+    ($($x:expr),+ $(,)?) => {
+        $crate::matrix_stable::Matrix::from_rows([$([$x]),+])
+    };
+}
+
 #[derive(Copy, Clone)]
 pub struct Matrix<'a, T: NumCast + Copy, const K: usize, const M: usize>(
     StaticCowVec<'a, T, { K * M }>,
@@ -33,6 +48,16 @@ where
         self.0.get_unchecked(n[0] + n[1] * K)
     }
 
+    pub fn from_rows(rows: [[T; K]; M]) -> Matrix<'static, T, K, M> {
+        let mut buffer = Matrix::<T, K, M>::zeros();
+        for i in 0..M {
+            for j in 0..K {
+                unsafe { *buffer.get_unchecked_mut([j, i]) = rows[i][j] }
+            }
+        }
+        buffer
+    }
+
     pub fn transpose(&self) -> Matrix<T, M, K>
     where
         StaticCowVec<'a, T, { M * K }>: Sized,
@@ -47,6 +72,127 @@ where
     }
 }
 
+impl<'a, T: num::Float, const K: usize> Matrix<'a, T, K, K>
+where
+    StaticCowVec<'a, T, { K * K }>: Sized,
+{
+    pub fn identity() -> Matrix<'static, T, K, K> {
+        let mut buffer = Matrix::<T, K, K>::zeros();
+        for i in 0..K {
+            unsafe { *buffer.get_unchecked_mut([i, i]) = T::one() }
+        }
+        buffer
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut lu = *self;
+        let mut det = T::one();
+        for k in 0..K {
+            let mut piv = k;
+            let mut max = unsafe { *lu.get_unchecked([k, k]) }.abs();
+            for i in (k + 1)..K {
+                let v = unsafe { *lu.get_unchecked([i, k]) }.abs();
+                if v > max {
+                    max = v;
+                    piv = i;
+                }
+            }
+            if max < T::epsilon() {
+                return T::zero();
+            }
+            if piv != k {
+                for j in 0..K {
+                    unsafe {
+                        let a = *lu.get_unchecked([k, j]);
+                        let b = *lu.get_unchecked([piv, j]);
+                        *lu.get_unchecked_mut([k, j]) = b;
+                        *lu.get_unchecked_mut([piv, j]) = a;
+                    }
+                }
+                det = -det;
+            }
+            let pivot = unsafe { *lu.get_unchecked([k, k]) };
+            det = det * pivot;
+            for i in (k + 1)..K {
+                let factor = unsafe { *lu.get_unchecked([i, k]) } / pivot;
+                for j in k..K {
+                    unsafe {
+                        let upd = *lu.get_unchecked([i, j]) - factor * *lu.get_unchecked([k, j]);
+                        *lu.get_unchecked_mut([i, j]) = upd;
+                    }
+                }
+            }
+        }
+        det
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<'static, T, K, K>> {
+        let mut lu = *self;
+        let mut perm = [0usize; K];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = i;
+        }
+        for k in 0..K {
+            let mut piv = k;
+            let mut max = unsafe { *lu.get_unchecked([k, k]) }.abs();
+            for i in (k + 1)..K {
+                let v = unsafe { *lu.get_unchecked([i, k]) }.abs();
+                if v > max {
+                    max = v;
+                    piv = i;
+                }
+            }
+            if max < T::epsilon() {
+                return None;
+            }
+            if piv != k {
+                for j in 0..K {
+                    unsafe {
+                        let a = *lu.get_unchecked([k, j]);
+                        let b = *lu.get_unchecked([piv, j]);
+                        *lu.get_unchecked_mut([k, j]) = b;
+                        *lu.get_unchecked_mut([piv, j]) = a;
+                    }
+                }
+                perm.swap(k, piv);
+            }
+            let pivot = unsafe { *lu.get_unchecked([k, k]) };
+            for i in (k + 1)..K {
+                let factor = unsafe { *lu.get_unchecked([i, k]) } / pivot;
+                unsafe { *lu.get_unchecked_mut([i, k]) = factor }
+                for j in (k + 1)..K {
+                    unsafe {
+                        let upd = *lu.get_unchecked([i, j]) - factor * *lu.get_unchecked([k, j]);
+                        *lu.get_unchecked_mut([i, j]) = upd;
+                    }
+                }
+            }
+        }
+        let mut inverse = Matrix::<T, K, K>::zeros();
+        let mut x = [T::zero(); K];
+        for col in 0..K {
+            for i in 0..K {
+                let mut sum = if perm[i] == col { T::one() } else { T::zero() };
+                for j in 0..i {
+                    sum = sum - unsafe { *lu.get_unchecked([i, j]) } * x[j];
+                }
+                x[i] = sum;
+            }
+            for i in (0..K).rev() {
+                let mut sum = x[i];
+                for j in (i + 1)..K {
+                    sum = sum - unsafe { *lu.get_unchecked([i, j]) } * x[j];
+                }
+                x[i] = sum / unsafe { *lu.get_unchecked([i, i]) };
+            }
+            for i in 0..K {
+                unsafe { *inverse.get_unchecked_mut([i, col]) = x[i] }
+            }
+        }
+        Some(inverse)
+    }
+}
+
 impl<'a, T: NumCast + Copy, const K: usize, const M: usize> Deref for Matrix<'a, T, K, M>
 where
     StaticCowVec<'a, T, { K * M }>: Sized,
@@ -124,41 +270,368 @@ impl<'a, T: Copy + NumCast, const K: usize, const M: usize> From<[T; K * M]>
     }
 }
 
-impl<'a, 'b, const M: usize, const N: usize, const K: usize> Mul<Matrix<'b, f32, N, K>>
-    for Matrix<'a, f32, K, M>
+pub trait Gemm: NumCast + Copy {
+    unsafe fn gemm(m: usize, n: usize, k: usize, a: *const Self, b: *const Self, c: *mut Self);
+}
+
+#[cfg(not(feature = "no-blas"))]
+impl Gemm for f32 {
+    unsafe fn gemm(m: usize, n: usize, k: usize, a: *const Self, b: *const Self, c: *mut Self) {
+        cblas_sgemm(
+            cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
+            cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
+            cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
+            m as i32,
+            n as i32,
+            k as i32,
+            1.,
+            a,
+            k as i32,
+            b,
+            n as i32,
+            0.,
+            c,
+            n as i32,
+        )
+    }
+}
+
+#[cfg(not(feature = "no-blas"))]
+impl Gemm for f64 {
+    unsafe fn gemm(m: usize, n: usize, k: usize, a: *const Self, b: *const Self, c: *mut Self) {
+        cblas_dgemm(
+            cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
+            cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
+            cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
+            m as i32,
+            n as i32,
+            k as i32,
+            1.,
+            a,
+            k as i32,
+            b,
+            n as i32,
+            0.,
+            c,
+            n as i32,
+        )
+    }
+}
+
+#[cfg(feature = "no-blas")]
+unsafe fn gemm_fallback<T: Add<Output = T> + Mul<Output = T> + Copy>(
+    m: usize,
+    n: usize,
+    k: usize,
+    a: *const T,
+    b: *const T,
+    c: *mut T,
+) {
+    const TILE: usize = 32;
+    let mut ii = 0;
+    while ii < m {
+        let mut jj = 0;
+        while jj < n {
+            let mut pp = 0;
+            while pp < k {
+                for i in ii..(ii + TILE).min(m) {
+                    for j in jj..(jj + TILE).min(n) {
+                        let mut acc = *c.add(i * n + j);
+                        for p in pp..(pp + TILE).min(k) {
+                            acc = acc + *a.add(i * k + p) * *b.add(p * n + j);
+                        }
+                        *c.add(i * n + j) = acc;
+                    }
+                }
+                pp += TILE;
+            }
+            jj += TILE;
+        }
+        ii += TILE;
+    }
+}
+
+#[cfg(feature = "no-blas")]
+impl Gemm for f32 {
+    unsafe fn gemm(m: usize, n: usize, k: usize, a: *const Self, b: *const Self, c: *mut Self) {
+        gemm_fallback(m, n, k, a, b, c)
+    }
+}
+
+#[cfg(feature = "no-blas")]
+impl Gemm for f64 {
+    unsafe fn gemm(m: usize, n: usize, k: usize, a: *const Self, b: *const Self, c: *mut Self) {
+        gemm_fallback(m, n, k, a, b, c)
+    }
+}
+
+impl<'a, 'b, T: Gemm, const M: usize, const N: usize, const K: usize> Mul<Matrix<'b, T, N, K>>
+    for Matrix<'a, T, K, M>
+where
+    StaticCowVec<'a, T, { K * M }>: Sized,
+    StaticCowVec<'a, T, { N * K }>: Sized,
+    StaticCowVec<'a, T, { N * M }>: Sized,
+{
+    type Output = Matrix<'static, T, N, M>;
+    fn mul(self, other: Matrix<'b, T, N, K>) -> Self::Output {
+        let mut buffer = Self::Output::zeros();
+        unsafe { T::gemm(M, N, K, self.as_ptr(), other.as_ptr(), buffer.as_mut_ptr()) }
+        buffer
+    }
+}
+
+pub struct SparseMatrix<T: NumCast + Copy, const K: usize, const M: usize> {
+    values: Vec<T>,
+    col_indices: Vec<usize>,
+    row_ptr: Vec<usize>,
+}
+
+impl<T: NumCast + Copy, const K: usize, const M: usize> SparseMatrix<T, K, M> {
+    pub fn new() -> Self {
+        SparseMatrix {
+            values: Vec::new(),
+            col_indices: Vec::new(),
+            row_ptr: vec![0; K + 1],
+        }
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn reserve(&mut self, nnz: usize) {
+        self.values.reserve(nnz);
+        self.col_indices.reserve(nnz);
+    }
+
+    pub fn from_dense(dense: &Matrix<T, K, M>, threshold: T) -> Self
+    where
+        T: num::Float,
+        StaticCowVec<'static, T, { K * M }>: Sized,
+    {
+        let mut sparse = Self::new();
+        for row in 0..K {
+            for col in 0..M {
+                let v = unsafe { *dense.get_unchecked([row, col]) };
+                if v.abs() > threshold {
+                    sparse.values.push(v);
+                    sparse.col_indices.push(col);
+                }
+            }
+            sparse.row_ptr[row + 1] = sparse.values.len();
+        }
+        sparse
+    }
+
+    pub fn to_dense(&self) -> Matrix<'static, T, K, M>
+    where
+        StaticCowVec<'static, T, { K * M }>: Sized,
+    {
+        let mut dense = Matrix::<T, K, M>::zeros();
+        for row in 0..K {
+            for p in self.row_ptr[row]..self.row_ptr[row + 1] {
+                unsafe { *dense.get_unchecked_mut([row, self.col_indices[p]]) = self.values[p] }
+            }
+        }
+        dense
+    }
+}
+
+impl<T: NumCast + Copy, const K: usize, const M: usize> Default for SparseMatrix<T, K, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'b, T, const M: usize, const N: usize, const K: usize> Mul<Matrix<'b, T, N, K>>
+    for SparseMatrix<T, K, M>
 where
-    StaticCowVec<'a, f32, { K * M }>: Sized,
-    StaticCowVec<'a, f32, { N * K }>: Sized,
-    StaticCowVec<'a, f32, { N * M }>: Sized,
+    T: NumCast + Copy + Add<Output = T> + Mul<Output = T>,
+    StaticCowVec<'b, T, { N * K }>: Sized,
+    StaticCowVec<'static, T, { N * M }>: Sized,
 {
-    type Output = Matrix<'static, f32, N, M>;
-    fn mul(self, other: Matrix<'b, f32, N, K>) -> Self::Output {
+    type Output = Matrix<'static, T, N, M>;
+    fn mul(self, other: Matrix<'b, T, N, K>) -> Self::Output {
         let mut buffer = Self::Output::zeros();
-        unsafe {
-            cblas_sgemm(
-                cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
-                cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
-                cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
-                M as i32,
-                N as i32,
-                K as i32,
-                1.,
-                self.as_ptr(),
-                K as i32,
-                other.as_ptr(),
-                N as i32,
-                0.,
-                buffer.as_mut_ptr(),
-                N as i32,
-            )
+        for row in 0..K {
+            for p in self.row_ptr[row]..self.row_ptr[row + 1] {
+                let col = self.col_indices[p];
+                let val = self.values[p];
+                for j in 0..N {
+                    unsafe {
+                        let acc = *buffer.0.get_unchecked(col * N + j)
+                            + val * *other.0.get_unchecked(row * N + j);
+                        *buffer.0.get_unchecked_mut(col * N + j) = acc;
+                    }
+                }
+            }
         }
         buffer
     }
 }
 
+#[cfg(feature = "convert-nalgebra")]
+mod convert_nalgebra {
+    use super::*;
+    use nalgebra::SMatrix;
+
+    impl<'a, T, const K: usize, const M: usize> From<Matrix<'a, T, K, M>> for SMatrix<T, M, K>
+    where
+        T: NumCast + Copy + nalgebra::Scalar + num::Zero,
+        StaticCowVec<'a, T, { K * M }>: Sized,
+    {
+        fn from(m: Matrix<'a, T, K, M>) -> Self {
+            let mut out = SMatrix::<T, M, K>::zeros();
+            for a in 0..K {
+                for b in 0..M {
+                    out[(b, a)] = unsafe { *m.get_unchecked([a, b]) };
+                }
+            }
+            out
+        }
+    }
+
+    impl<T, const K: usize, const M: usize> From<SMatrix<T, M, K>> for Matrix<'static, T, K, M>
+    where
+        T: NumCast + Copy + nalgebra::Scalar + num::Zero,
+        StaticCowVec<'static, T, { K * M }>: Sized,
+    {
+        fn from(m: SMatrix<T, M, K>) -> Self {
+            let mut out = Matrix::<T, K, M>::zeros();
+            for a in 0..K {
+                for b in 0..M {
+                    unsafe { *out.get_unchecked_mut([a, b]) = m[(b, a)] }
+                }
+            }
+            out
+        }
+    }
+}
+
+#[cfg(feature = "io")]
+pub mod io {
+    use super::*;
+    use std::io::{BufRead, Write};
+
+    fn invalid(msg: &str) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, msg.to_string())
+    }
+
+    impl<'a, T: NumCast + Copy, const K: usize, const M: usize> Matrix<'a, T, K, M>
+    where
+        StaticCowVec<'a, T, { K * M }>: Sized,
+    {
+        pub fn read_mtx<R: BufRead>(reader: R) -> std::io::Result<Matrix<'static, T, K, M>>
+        where
+            T: std::str::FromStr,
+        {
+            let mut lines = reader.lines();
+            let banner = lines
+                .next()
+                .ok_or_else(|| invalid("missing %%MatrixMarket banner"))??;
+            if !banner.starts_with("%%MatrixMarket") {
+                return Err(invalid("missing %%MatrixMarket banner"));
+            }
+            let coordinate = banner.contains("coordinate");
+
+            let header = loop {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| invalid("missing dimension header"))??;
+                let trimmed = line.trim();
+                if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                    break trimmed.to_string();
+                }
+            };
+            let mut dims = header.split_whitespace();
+            let rows: usize = dims
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| invalid("invalid dimension header"))?;
+            let cols: usize = dims
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| invalid("invalid dimension header"))?;
+            if rows != M || cols != K {
+                return Err(invalid("matrix dimensions do not match const generics"));
+            }
+
+            let mut buffer = Matrix::<T, K, M>::zeros();
+            if coordinate {
+                for line in lines {
+                    let line = line?;
+                    let trimmed = line.trim();
+                    if trimmed.is_empty() || trimmed.starts_with('%') {
+                        continue;
+                    }
+                    let mut entry = trimmed.split_whitespace();
+                    let i: usize = entry
+                        .next()
+                        .and_then(|s| s.parse().ok())
+                        .ok_or_else(|| invalid("invalid coordinate entry"))?;
+                    let j: usize = entry
+                        .next()
+                        .and_then(|s| s.parse().ok())
+                        .ok_or_else(|| invalid("invalid coordinate entry"))?;
+                    let v: T = entry
+                        .next()
+                        .and_then(|s| s.parse().ok())
+                        .ok_or_else(|| invalid("invalid coordinate entry"))?;
+                    if i < 1 || i > M || j < 1 || j > K {
+                        return Err(invalid("coordinate entry out of bounds"));
+                    }
+                    unsafe { *buffer.get_unchecked_mut([j - 1, i - 1]) = v }
+                }
+            } else {
+                let mut idx = 0;
+                for line in lines {
+                    let line = line?;
+                    let trimmed = line.trim();
+                    if trimmed.is_empty() || trimmed.starts_with('%') {
+                        continue;
+                    }
+                    for token in trimmed.split_whitespace() {
+                        if idx >= K * M {
+                            return Err(invalid("too many entries for declared dimensions"));
+                        }
+                        let v: T = token
+                            .parse()
+                            .map_err(|_| invalid("invalid array entry"))?;
+                        // MatrixMarket array is column-major over the M×K logical
+                        // matrix, so the idx-th value is column `idx / M`, row `idx % M`.
+                        let col = idx / M;
+                        let row = idx % M;
+                        unsafe { *buffer.get_unchecked_mut([col, row]) = v }
+                        idx += 1;
+                    }
+                }
+                if idx != K * M {
+                    return Err(invalid("too few entries for declared dimensions"));
+                }
+            }
+            Ok(buffer)
+        }
+
+        pub fn write_mtx<W: Write>(&self, mut writer: W) -> std::io::Result<()>
+        where
+            T: std::fmt::Display,
+        {
+            writeln!(writer, "%%MatrixMarket matrix array real general")?;
+            writeln!(writer, "{} {}", M, K)?;
+            for col in 0..K {
+                for row in 0..M {
+                    writeln!(writer, "{}", unsafe { self.get_unchecked([col, row]) })?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Matrix;
+    use crate::{matrix, vector};
 
     #[test]
     fn zero() {
@@ -168,6 +641,24 @@ mod tests {
         assert!(**m == **n)
     }
 
+    #[test]
+    fn macros() {
+        let m: Matrix<f32, 3, 2> = matrix![1., 2., 3.; 4., 5., 6.];
+        let flat: Matrix<f32, 3, 2> = [1., 2., 3., 4., 5., 6.].into();
+        assert_eq!(**m, **flat);
+        assert_eq!(**m, [1., 2., 3., 4., 5., 6.]);
+
+        let v: Matrix<f32, 1, 3> = vector![7., 8., 9.];
+        assert_eq!(**v, [7., 8., 9.]);
+    }
+
+    #[test]
+    fn macro_mul() {
+        let a: Matrix<f32, 2, 2> = matrix![1., 2.; 3., 4.];
+        let b: Matrix<f32, 2, 2> = matrix![5., 6.; 7., 8.];
+        assert_eq!(**(a * b), [19., 22., 43., 50.]);
+    }
+
     #[test]
     fn mul() {
         let m: Matrix<f32, 3, 2> = [1., 2., 3., 4., 5., 6.].into();
@@ -185,6 +676,116 @@ mod tests {
         assert_eq!(**(m * n), k);
     }
 
+    #[test]
+    fn mul_f64() {
+        let m: Matrix<f64, 3, 2> = [1., 2., 3., 4., 5., 6.].into();
+        let n: Matrix<f64, 2, 3> = [10., 11., 20., 21., 30., 31.].into();
+        let k = [140., 146., 320., 335.];
+
+        assert_eq!(**(m * n), k);
+    }
+
+    #[test]
+    fn identity() {
+        let i = Matrix::<f64, 3, 3>::identity();
+        assert_eq!(**i, [1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+    }
+
+    #[test]
+    fn determinant() {
+        let m: Matrix<f64, 2, 2> = [1., 2., 3., 4.].into();
+        assert!((m.determinant() - (1. * 4. - 3. * 2.)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse() {
+        let m: Matrix<f64, 2, 2> = [4., 2., 7., 6.].into();
+        let inv = m.inverse().unwrap();
+        let product = m * inv;
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((product[[i, j]] - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn singular_has_no_inverse() {
+        let m: Matrix<f64, 2, 2> = [1., 2., 2., 4.].into();
+        assert!(m.inverse().is_none());
+    }
+
+    #[cfg(feature = "io")]
+    #[test]
+    fn mtx_coordinate_matches_array() {
+        let coordinate = "\
+%%MatrixMarket matrix coordinate real general
+% a hand-written file
+3 2 3
+1 1 10
+2 1 20
+3 2 30
+";
+        let array = "\
+%%MatrixMarket matrix array real general
+3 2
+10
+20
+0
+0
+0
+30
+";
+        let from_coord = Matrix::<f64, 2, 3>::read_mtx(coordinate.as_bytes()).unwrap();
+        let from_array = Matrix::<f64, 2, 3>::read_mtx(array.as_bytes()).unwrap();
+        assert_eq!(**from_coord, [10., 0., 20., 0., 0., 30.]);
+        assert_eq!(**from_coord, **from_array);
+    }
+
+    #[cfg(feature = "io")]
+    #[test]
+    fn mtx_round_trip() {
+        let m: Matrix<f64, 3, 2> = [1., 2., 3., 4., 5., 6.].into();
+        let mut out = Vec::new();
+        m.write_mtx(&mut out).unwrap();
+        let back = Matrix::<f64, 3, 2>::read_mtx(&out[..]).unwrap();
+        assert_eq!(**m, **back);
+    }
+
+    #[test]
+    fn sparse_round_trip() {
+        let m: Matrix<f64, 3, 2> = [1., 0., 0., 0., 5., 0.].into();
+        let s = super::SparseMatrix::from_dense(&m, 0.);
+        assert_eq!(s.nnz(), 2);
+        assert_eq!(**s.to_dense(), **m);
+    }
+
+    #[test]
+    fn sparse_mul_matches_dense() {
+        let m: Matrix<f64, 3, 2> = [1., 2., 3., 4., 5., 6.].into();
+        let n: Matrix<f64, 2, 3> = [10., 11., 20., 21., 30., 31.].into();
+        let s = super::SparseMatrix::from_dense(&m, 0.);
+        assert_eq!(**(s * n), **(m * n));
+    }
+
+    #[cfg(feature = "convert-nalgebra")]
+    #[test]
+    fn nalgebra_round_trip() {
+        let m: Matrix<f64, 3, 2> = [1., 2., 3., 4., 5., 6.].into();
+        let na: nalgebra::SMatrix<f64, 2, 3> = m.into();
+        // m is the canonical 2×3 matrix [[1,2,3],[4,5,6]]; pin every element so an
+        // orientation flip can't hide behind a self-inverse round trip.
+        assert_eq!(na[(0, 0)], 1.);
+        assert_eq!(na[(0, 1)], 2.);
+        assert_eq!(na[(0, 2)], 3.);
+        assert_eq!(na[(1, 0)], 4.);
+        assert_eq!(na[(1, 1)], 5.);
+        assert_eq!(na[(1, 2)], 6.);
+        let back: Matrix<f64, 3, 2> = na.into();
+        assert_eq!(**m, **back);
+    }
+
     //#[test]
     //fn mul3() { // Doesn't work. Might just be an incorrect expected result.
     //    let m: Matrix<f32, 5, 6> = [